@@ -6,6 +6,7 @@ use std::process::Command;
 
 use ex::fs;
 use failure::{format_err, Error, ResultExt};
+use glob::Pattern;
 use rayon::prelude::*;
 use structopt::{self, clap::AppSettings, StructOpt};
 use tempfile::{NamedTempFile, TempDir};
@@ -28,6 +29,12 @@ struct DeployConfig {
     #[structopt(help = "Runs upgrade-charm on each individual charm instead of redeploying")]
     upgrade_charms: bool,
 
+    #[structopt(long = "channel")]
+    #[structopt(
+        help = "When used with --upgrade-charms, refresh charms to this channel instead of just running upgrade-charm"
+    )]
+    channel: Option<Channel>,
+
     #[structopt(long = "build")]
     #[structopt(help = "Build the bundle before deploying it. Requires `source:` to be defined")]
     build: bool,
@@ -36,8 +43,14 @@ struct DeployConfig {
     #[structopt(help = "How long to wait in seconds for model to stabilize before deploying it")]
     wait: u32,
 
+    #[structopt(long = "tail-logs")]
+    #[structopt(
+        help = "Stream filtered debug-log output for the bundle's apps while waiting for the model to stabilize"
+    )]
+    tail_logs: bool,
+
     #[structopt(short = "a", long = "app")]
-    #[structopt(help = "Select particular apps to deploy")]
+    #[structopt(help = "Select particular apps to deploy. Supports glob patterns, e.g. `istio-*`")]
     apps: Vec<String>,
 
     #[structopt(short = "b", long = "bundle", default_value = "bundle.yaml")]
@@ -53,7 +66,7 @@ struct DeployConfig {
 #[derive(StructOpt, Debug)]
 struct RemoveConfig {
     #[structopt(short = "a", long = "app")]
-    #[structopt(help = "Select particular apps to remove")]
+    #[structopt(help = "Select particular apps to remove. Supports glob patterns, e.g. `istio-*`")]
     apps: Vec<String>,
 
     #[structopt(short = "b", long = "bundle", default_value = "bundle.yaml")]
@@ -99,14 +112,55 @@ struct PromoteConfig {
     to: Channel,
 
     #[structopt(short = "e", long = "exclude")]
-    #[structopt(help = "Select particular apps to exclude from promoting")]
+    #[structopt(help = "Select particular apps to exclude from promoting. Supports glob patterns, e.g. `*-exporter`")]
     excluded: Vec<String>,
 }
 
-/// Interact with a bundle and the charms contained therein.
+/// CLI arguments for the `export` subcommand.
+#[derive(StructOpt, Debug)]
+struct ExportConfig {
+    #[structopt(short = "o", long = "output")]
+    #[structopt(help = "Where to write the generated bundle. Defaults to stdout")]
+    output: Option<PathBuf>,
+
+    #[structopt(short = "e", long = "exclude")]
+    #[structopt(help = "Select particular apps to exclude from the exported bundle. Supports glob patterns, e.g. `*-exporter`")]
+    excluded: Vec<String>,
+}
+
+/// CLI arguments for the `tail` subcommand.
+#[derive(StructOpt, Debug)]
+struct TailConfig {
+    #[structopt(short = "a", long = "app")]
+    #[structopt(help = "Only show logs for these apps. Supports glob patterns, e.g. `istio-*`")]
+    apps: Vec<String>,
+
+    #[structopt(short = "e", long = "exclude")]
+    #[structopt(help = "Exclude logs for these apps. Supports glob patterns, e.g. `*-exporter`")]
+    excluded: Vec<String>,
+
+    #[structopt(short = "b", long = "bundle", default_value = "bundle.yaml")]
+    #[structopt(help = "The bundle file whose applications to scope logs to")]
+    bundle: String,
+}
+
+/// Top-level CLI arguments, including options that apply across subcommands.
 #[derive(StructOpt, Debug)]
 #[structopt(raw(setting = "AppSettings::TrailingVarArg"))]
 #[structopt(raw(setting = "AppSettings::SubcommandRequiredElseHelp"))]
+struct Opt {
+    #[structopt(long = "jobs", global = true)]
+    #[structopt(
+        help = "How many charms to build/push in parallel for `deploy`/`publish`. Defaults to half the detected CPU count"
+    )]
+    jobs: Option<usize>,
+
+    #[structopt(subcommand)]
+    cmd: Config,
+}
+
+/// Interact with a bundle and the charms contained therein.
+#[derive(StructOpt, Debug)]
 enum Config {
     /// Deploys a bundle, optionally building and/or recreating it.
     ///
@@ -132,25 +186,67 @@ enum Config {
     /// Promotes a bundle and its charms from one channel to another
     #[structopt(name = "promote")]
     Promote(PromoteConfig),
+
+    /// Reconstructs a bundle from the currently deployed model.
+    ///
+    /// Runs `juju status` against the current model and writes out a
+    /// `bundle.yaml` with each application's deployed charm URL, channel,
+    /// resource revisions, unit count, and options, along with the
+    /// relations between the applications that were kept.
+    #[structopt(name = "export")]
+    Export(ExportConfig),
+
+    /// Streams `juju debug-log` scoped to a bundle's applications.
+    #[structopt(name = "tail")]
+    Tail(TailConfig),
 }
 
-/// Run `deploy` subcommand
-fn deploy(c: DeployConfig) -> Result<(), Error> {
-    println!("Building and deploying bundle from {}", c.bundle);
+/// Check whether `name` matches `selector`.
+///
+/// A selector with no wildcard characters is matched exactly, to keep
+/// existing behavior for plain app names. Otherwise it's treated as a
+/// shell-style glob, e.g. `istio-*`.
+fn selector_matches(selector: &str, name: &str) -> bool {
+    if !selector.contains(|c| c == '*' || c == '?' || c == '[') {
+        return selector == name;
+    }
 
-    let mut bundle = Bundle::load(c.bundle.clone())?;
+    match Pattern::new(selector) {
+        Ok(pattern) => pattern.matches(name),
+        Err(_) => selector == name,
+    }
+}
 
-    let applications = bundle.app_subset(c.apps.clone())?;
-    let build_count = applications.values().filter(|v| v.source.is_some()).count();
+/// Expand each selector into the application names it matches, erroring
+/// if a selector doesn't match anything so that typos are caught early.
+fn expand_selectors(selectors: &[String], names: &HashSet<&str>) -> Result<Vec<String>, Error> {
+    let mut matched = HashSet::new();
 
-    println!("Found {} total applications", applications.len());
-    println!("Found {} applications to build.\n", build_count);
+    for selector in selectors {
+        let mut selector_matched = false;
 
-    let temp_bundle = NamedTempFile::new()?;
+        for name in names {
+            if selector_matches(selector, name) {
+                matched.insert((*name).to_string());
+                selector_matched = true;
+            }
+        }
 
-    // Filter out relations that point to an application that was filtered out
-    bundle.relations = bundle
-        .relations
+        if !selector_matched {
+            return Err(format_err!(
+                "Selector `{}` did not match any applications",
+                selector
+            ));
+        }
+    }
+
+    Ok(matched.into_iter().collect())
+}
+
+/// Drop any relation where either endpoint refers to an application
+/// that isn't in `apps`.
+fn filter_relations(relations: Vec<Vec<String>>, apps: &HashSet<&str>) -> Vec<Vec<String>> {
+    relations
         .into_iter()
         .filter(|rels| {
             // Strip out interface name-style syntax before filtering,
@@ -158,67 +254,295 @@ fn deploy(c: DeployConfig) -> Result<(), Error> {
             rels.iter()
                 .map(|r| r.split(':').next().unwrap())
                 .collect::<HashSet<_>>()
-                .is_subset(&applications.keys().map(String::as_ref).collect())
+                .is_subset(apps)
         })
-        .collect();
-
-    let mapped: Result<HashMap<String, Application>, Error> = applications
-        .par_iter()
-        .map(|(name, application)| {
-            let mut new_application = application.clone();
-
-            new_application.charm = match (c.build, &application.charm, &application.source) {
-                // If a charm URL was defined and either the `--build` flag wasn't passed or
-                // there's no `source` property, deploy the charm URL
-                (false, Some(charm), _) | (_, Some(charm), None) => Some(charm.clone()),
-
-                // Either `charm` or `source` must be set
-                (_, None, None) => {
-                    return Err(format_err!(
-                        "Application {} has neither `charm` nor `source` set.",
-                        name
-                    ));
+        .collect()
+}
+
+/// Parse `juju status --format=yaml` output into the applications and
+/// relations of a `Bundle`, picking up each application's deployed charm
+/// URL, channel, resource revisions, unit count, and options.
+fn bundle_from_status(yaml: &[u8]) -> Result<Bundle, Error> {
+    let status: serde_yaml::Value = serde_yaml::from_slice(yaml)?;
+
+    let raw_apps = status
+        .get("applications")
+        .and_then(|apps| apps.as_mapping())
+        .ok_or_else(|| format_err!("`juju status` output had no `applications` section"))?;
+
+    let mut applications = HashMap::new();
+    let mut relations = Vec::new();
+    let mut seen_relations = HashSet::new();
+
+    for (name, raw_app) in raw_apps {
+        let name = name
+            .as_str()
+            .ok_or_else(|| format_err!("Application name was not a string"))?
+            .to_string();
+
+        let charm = raw_app
+            .get("charm")
+            .and_then(|v| v.as_str())
+            .map(str::parse)
+            .transpose()?;
+
+        let channel = raw_app
+            .get("charm-channel")
+            .and_then(|v| v.as_str())
+            .map(str::parse)
+            .transpose()?;
+
+        let num_units = raw_app
+            .get("units")
+            .and_then(|v| v.as_mapping())
+            .map(|units| units.len() as u32);
+
+        let resources = raw_app
+            .get("resources")
+            .and_then(|v| v.as_mapping())
+            .map(|resources| {
+                resources
+                    .iter()
+                    .filter_map(|(resource, info)| {
+                        let revision = info.get("revision")?.as_i64()?;
+                        Some((resource.as_str()?.to_string(), revision.to_string()))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let options = raw_app
+            .get("options")
+            .and_then(|v| v.as_mapping())
+            .map(|options| {
+                options
+                    .iter()
+                    .filter_map(|(key, value)| Some((key.as_str()?.to_string(), value.clone())))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        // Derive relations from each application's endpoint -> related
+        // applications mapping. Both sides of a relation list each other,
+        // so dedup on the unordered pair of plain app names (ignoring the
+        // `:endpoint` suffix, which only one side's iteration will have)
+        // while still emitting one qualified form.
+        if let Some(endpoints) = raw_app.get("relations").and_then(|v| v.as_mapping()) {
+            for (endpoint, related_apps) in endpoints {
+                let endpoint = match endpoint.as_str() {
+                    Some(endpoint) => endpoint,
+                    None => continue,
+                };
+
+                for related_app in related_apps.as_sequence().into_iter().flatten() {
+                    let related_app = match related_app.as_str() {
+                        Some(related_app) => related_app,
+                        None => continue,
+                    };
+
+                    let mut dedup_key = vec![name.clone(), related_app.to_string()];
+                    dedup_key.sort();
+
+                    if !seen_relations.insert(dedup_key) {
+                        continue;
+                    }
+
+                    let mut relation = vec![format!("{}:{}", name, endpoint), related_app.to_string()];
+                    relation.sort();
+                    relations.push(relation);
                 }
+            }
+        }
 
-                // If the charm source was defined and either the `--build` flag was passed, or
-                // if there's no `charm` property, build the charm
-                (true, _, Some(source)) | (_, None, Some(source)) => {
-                    println!("Building {}", name);
+        applications.insert(
+            name,
+            Application {
+                charm,
+                channel,
+                num_units,
+                resources,
+                options,
+                ..Default::default()
+            },
+        );
+    }
 
-                    let build_dir = paths::charm_build_dir();
+    Ok(Bundle {
+        applications,
+        relations,
+        ..Default::default()
+    })
+}
 
-                    // If `source` starts with `.`, it's a relative path from the bundle we're
-                    // deploying. Otherwise, look in `CHARM_SOURCE_DIR` for it.
-                    let charm_path = if source.starts_with('.') {
-                        PathBuf::from(&c.bundle).parent().unwrap().join(source)
-                    } else {
-                        paths::charm_source_dir().join(source)
-                    };
+/// Build a rayon thread pool scoped to `jobs` threads, defaulting to half
+/// the detected CPU count when unset. Used to bound how many charms are
+/// built/pushed at once instead of leaving it unbounded.
+fn build_thread_pool(jobs: Option<usize>) -> Result<rayon::ThreadPool, Error> {
+    if jobs == Some(0) {
+        return Err(format_err!("--jobs must be greater than zero"));
+    }
 
-                    let charm = CharmSource::load(&charm_path)?;
+    let num_threads = jobs.unwrap_or_else(|| (num_cpus::get() / 2).max(1));
 
-                    charm.build(name)?;
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .build()
+        .map_err(|e| format_err!("Failed to build thread pool: {}", e))
+}
 
-                    for (name, resource) in charm.metadata.resources {
-                        if let Some(source) = resource.upstream_source {
-                            new_application.resources.entry(name).or_insert(source);
-                        }
+/// Build the `juju debug-log` arguments that scope output to `apps`,
+/// dropping any that match `excluded`. Errors if every app ends up
+/// excluded, since `juju debug-log` with no `--include` filters shows
+/// everything rather than nothing.
+fn debug_log_args(apps: &[String], excluded: &[String]) -> Result<Vec<String>, Error> {
+    let mut args = vec!["debug-log".to_string(), "--tail".to_string()];
+    let mut included = 0;
+
+    for app in apps {
+        if excluded.iter().any(|e| selector_matches(e, app)) {
+            continue;
+        }
+        args.push("--include".to_string());
+        args.push(format!("unit-{}-*", app));
+        included += 1;
+    }
+
+    if !apps.is_empty() && included == 0 {
+        return Err(format_err!(
+            "All applications were excluded from logging; nothing to tail"
+        ));
+    }
+
+    Ok(args)
+}
+
+/// Refresh each application to a charm from `channel`, carrying over its
+/// resource revisions, rather than tearing down and redeploying it.
+fn refresh_charms(applications: &HashMap<String, Application>, channel: Channel) -> Result<(), Error> {
+    for (name, application) in applications {
+        println!("Refreshing {} to the {} channel", name, channel);
+
+        let mut args = vec![
+            "refresh".to_string(),
+            name.clone(),
+            format!("--channel={}", channel),
+        ];
+
+        for (resource, revision) in &application.resources {
+            args.push("--resource".to_string());
+            args.push(format!("{}={}", resource, revision));
+        }
+
+        let exit_status = Command::new("juju").args(&args).spawn()?.wait()?;
+
+        if !exit_status.success() {
+            return Err(format_err!(
+                "Encountered an error while refreshing {}: {}",
+                name,
+                exit_status.to_string()
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Run `deploy` subcommand
+fn deploy(c: DeployConfig, jobs: Option<usize>) -> Result<(), Error> {
+    if c.channel.is_some() && !c.upgrade_charms {
+        return Err(format_err!(
+            "To use --channel, you must set the --upgrade-charms flag as well."
+        ));
+    }
+
+    println!("Building and deploying bundle from {}", c.bundle);
+
+    let mut bundle = Bundle::load(c.bundle.clone())?;
+
+    let apps = if c.apps.is_empty() {
+        c.apps.clone()
+    } else {
+        let all_apps: HashSet<&str> = bundle.applications.keys().map(String::as_ref).collect();
+        expand_selectors(&c.apps, &all_apps)?
+    };
+
+    let applications = bundle.app_subset(apps)?;
+    let build_count = applications.values().filter(|v| v.source.is_some()).count();
+
+    println!("Found {} total applications", applications.len());
+    println!("Found {} applications to build.\n", build_count);
+
+    let temp_bundle = NamedTempFile::new()?;
+
+    bundle.relations = filter_relations(
+        bundle.relations,
+        &applications.keys().map(String::as_ref).collect(),
+    );
+
+    let pool = build_thread_pool(jobs)?;
+
+    let mapped: Result<HashMap<String, Application>, Error> = pool.install(|| {
+        applications
+            .par_iter()
+            .map(|(name, application)| {
+                let mut new_application = application.clone();
+
+                new_application.charm = match (c.build, &application.charm, &application.source) {
+                    // If a charm URL was defined and either the `--build` flag wasn't passed or
+                    // there's no `source` property, deploy the charm URL
+                    (false, Some(charm), _) | (_, Some(charm), None) => Some(charm.clone()),
+
+                    // Either `charm` or `source` must be set
+                    (_, None, None) => {
+                        return Err(format_err!(
+                            "Application {} has neither `charm` nor `source` set.",
+                            name
+                        ));
                     }
 
-                    Some(CharmURL::from_path(build_dir.join(charm.metadata.name)))
-                }
-            };
+                    // If the charm source was defined and either the `--build` flag was passed, or
+                    // if there's no `charm` property, build the charm
+                    (true, _, Some(source)) | (_, None, Some(source)) => {
+                        println!("Building {}", name);
 
-            Ok((name.clone(), new_application))
-        })
-        .collect();
+                        let build_dir = paths::charm_build_dir();
+
+                        // If `source` starts with `.`, it's a relative path from the bundle we're
+                        // deploying. Otherwise, look in `CHARM_SOURCE_DIR` for it.
+                        let charm_path = if source.starts_with('.') {
+                            PathBuf::from(&c.bundle).parent().unwrap().join(source)
+                        } else {
+                            paths::charm_source_dir().join(source)
+                        };
+
+                        let charm = CharmSource::load(&charm_path)?;
+
+                        charm.build(name)?;
+
+                        for (name, resource) in charm.metadata.resources {
+                            if let Some(source) = resource.upstream_source {
+                                new_application.resources.entry(name).or_insert(source);
+                            }
+                        }
+
+                        Some(CharmURL::from_path(build_dir.join(charm.metadata.name)))
+                    }
+                };
+
+                Ok((name.clone(), new_application))
+            })
+            .collect()
+    });
 
     bundle.applications = mapped?;
 
     // If we're only upgrading charms, we can skip the rest of the logic
     // that is concerned with tearing down and/or deploying the charms.
     if c.upgrade_charms {
-        return Ok(bundle.upgrade_charms()?);
+        return match c.channel {
+            Some(channel) => refresh_charms(&bundle.applications, channel),
+            None => Ok(bundle.upgrade_charms()?),
+        };
     }
 
     bundle.save(temp_bundle.path())?;
@@ -234,10 +558,23 @@ fn deploy(c: DeployConfig) -> Result<(), Error> {
     if c.wait > 0 {
         println!("\n\nWaiting for stability before deploying.");
 
-        let exit_status = Command::new("juju")
+        let mut log_child = if c.tail_logs {
+            let apps: Vec<String> = applications.keys().cloned().collect();
+            Some(Command::new("juju").args(&debug_log_args(&apps, &[])?).spawn()?)
+        } else {
+            None
+        };
+
+        let wait_result = Command::new("juju")
             .args(&["wait", "-wv", "-t", &c.wait.to_string()])
-            .spawn()?
-            .wait()?;
+            .spawn()
+            .and_then(|mut child| child.wait());
+
+        if let Some(mut child) = log_child.take() {
+            child.kill().ok();
+        }
+
+        let exit_status = wait_result?;
 
         if !exit_status.success() {
             return Err(format_err!(
@@ -268,7 +605,15 @@ fn deploy(c: DeployConfig) -> Result<(), Error> {
 /// Run `remove` subcommand
 fn remove(c: RemoveConfig) -> Result<(), Error> {
     let bundle = Bundle::load(c.bundle)?;
-    for name in bundle.app_subset(c.apps)?.keys() {
+
+    let apps = if c.apps.is_empty() {
+        c.apps
+    } else {
+        let all_apps: HashSet<&str> = bundle.applications.keys().map(String::as_ref).collect();
+        expand_selectors(&c.apps, &all_apps)?
+    };
+
+    for name in bundle.app_subset(apps)?.keys() {
         Command::new("juju")
             .args(&["remove-application", name])
             .spawn()?
@@ -278,7 +623,7 @@ fn remove(c: RemoveConfig) -> Result<(), Error> {
 }
 
 /// Run `publish` subcommand
-fn publish(c: PublishConfig) -> Result<(), Error> {
+fn publish(c: PublishConfig, jobs: Option<usize>) -> Result<(), Error> {
     if c.prune && !c.serial {
         return Err(format_err!(
             "To use --prune, you must set the --serial flag as well."
@@ -353,11 +698,12 @@ fn publish(c: PublishConfig) -> Result<(), Error> {
     // Build each charm, upload it to the store, then promote that
     // revision to edge. Return a list of the revision URLs, so that
     // we can generate a bundle with those exact revisions to upload.
-    let revisions: Result<Vec<(String, String)>, Error> = if c.serial {
-        apps.iter().map(publish_handler).collect()
-    } else {
-        apps.par_iter().map(publish_handler).collect()
-    };
+    // `--serial` is shorthand for `--jobs 1`.
+    let jobs = if c.serial { Some(1) } else { jobs };
+    let pool = build_thread_pool(jobs)?;
+
+    let revisions: Result<Vec<(String, String)>, Error> =
+        pool.install(|| apps.par_iter().map(publish_handler).collect());
 
     // Make a copy of the bundle with exact revisions of each charm
     let mut new_bundle = bundle.clone();
@@ -395,8 +741,15 @@ fn promote(c: PromoteConfig) -> Result<(), Error> {
 
     println!("Found bundle revision {}", revision);
 
+    let excluded: HashSet<String> = if c.excluded.is_empty() {
+        HashSet::new()
+    } else {
+        let all_apps: HashSet<&str> = bundle.applications.keys().map(String::as_ref).collect();
+        expand_selectors(&c.excluded, &all_apps)?.into_iter().collect()
+    };
+
     for (name, app) in &bundle.applications {
-        if c.excluded.contains(name) || app.source.is_none() {
+        if excluded.contains(name) || app.source.is_none() {
             continue;
         }
         println!("Promoting {} to {:?}.", name, c.to);
@@ -410,11 +763,82 @@ fn promote(c: PromoteConfig) -> Result<(), Error> {
     Ok(())
 }
 
+/// Run `export` subcommand
+fn export(c: ExportConfig) -> Result<(), Error> {
+    eprintln!("Exporting bundle from the live model");
+
+    let output = Command::new("juju")
+        .args(&["status", "--format=yaml"])
+        .output()?;
+
+    if !output.status.success() {
+        return Err(format_err!(
+            "Encountered an error while getting model status: {}",
+            output.status.to_string()
+        ));
+    }
+
+    let mut bundle = bundle_from_status(&output.stdout)?;
+
+    let excluded: HashSet<String> = if c.excluded.is_empty() {
+        HashSet::new()
+    } else {
+        let all_apps: HashSet<&str> = bundle.applications.keys().map(String::as_ref).collect();
+        expand_selectors(&c.excluded, &all_apps)?.into_iter().collect()
+    };
+
+    bundle.applications.retain(|name, _| !excluded.contains(name));
+
+    bundle.relations = filter_relations(
+        bundle.relations,
+        &bundle.applications.keys().map(String::as_ref).collect(),
+    );
+
+    match c.output {
+        Some(path) => bundle.save(path)?,
+        None => print!("{}", serde_yaml::to_string(&bundle)?),
+    }
+
+    Ok(())
+}
+
+/// Run `tail` subcommand
+fn tail(c: TailConfig) -> Result<(), Error> {
+    let bundle = Bundle::load(c.bundle)?;
+
+    let apps: Vec<String> = if c.apps.is_empty() {
+        bundle.applications.keys().cloned().collect()
+    } else {
+        let all_apps: HashSet<&str> = bundle.applications.keys().map(String::as_ref).collect();
+        expand_selectors(&c.apps, &all_apps)?
+    };
+
+    println!("Tailing logs for: {}", apps.join(", "));
+
+    let exit_status = Command::new("juju")
+        .args(&debug_log_args(&apps, &c.excluded)?)
+        .spawn()?
+        .wait()?;
+
+    if !exit_status.success() {
+        return Err(format_err!(
+            "Encountered an error while tailing logs: {}",
+            exit_status.to_string()
+        ));
+    }
+
+    Ok(())
+}
+
 fn main() -> Result<(), Error> {
-    match Config::from_args() {
-        Config::Deploy(c) => deploy(c),
+    let opt = Opt::from_args();
+
+    match opt.cmd {
+        Config::Deploy(c) => deploy(c, opt.jobs),
         Config::Remove(c) => remove(c),
-        Config::Publish(c) => publish(c),
+        Config::Publish(c) => publish(c, opt.jobs),
         Config::Promote(c) => promote(c),
+        Config::Export(c) => export(c),
+        Config::Tail(c) => tail(c),
     }
 }